@@ -1,6 +1,8 @@
 // Exercises all dangerous API patterns detected by find_dangerous_apis.py.
-// All patterns appear within 15 lines of the SecretKey type so that
-// has_sensitive_context returns True and confidence = "likely".
+// Confidence is derived from taint provenance, not textual proximity: a
+// secret owned and constructed in the same function ("likely") vs. one
+// that crossed the function boundary via a &/&mut parameter or another
+// call's return value ("possible") — see _seed_params/_analyze_function.
 use std::mem::{self, ManuallyDrop};
 use std::ptr;
 
@@ -33,6 +35,15 @@ unsafe fn uninit_secret() -> SecretKey {
     mem::uninitialized()
 }
 
+// B4: the enclosing function's own return type is `()`, not SecretKey — the
+// call is only dangerous because of the sensitive annotation on the `let`
+// it's assigned into.
+#[allow(deprecated)]
+fn uninit_secret_into_a_sensitive_let() {
+    let key: SecretKey = unsafe { mem::uninitialized() };
+    drop(key);
+}
+
 // B5: Box::into_raw — raw pointer escapes Drop
 fn raw_secret(key: SecretKey) -> *mut SecretKey {
     Box::into_raw(Box::new(key))
@@ -58,6 +69,30 @@ fn slice_secret(key: &SecretKey) -> &[u8] {
     unsafe { std::slice::from_raw_parts(key.0.as_ptr(), 32) }
 }
 
+// B6: a volatile write with no fence after it is still a dead-store risk —
+// the optimizer is free to reorder or drop it without the fence forcing
+// it to be observed.
+fn wipe_secret_no_fence(key: &mut SecretKey) {
+    unsafe { ptr::write_volatile(key as *mut SecretKey, SecretKey([0u8; 32])); }
+}
+
+// B6: plain field assignment to zero — a non-volatile store, same as
+// ptr::write_bytes above.
+fn wipe_secret_by_assignment(key: &mut SecretKey) {
+    key.0 = [0u8; 32];
+}
+
+// B1: a secret parameter following a nested-paren-typed parameter —
+// regression for the balanced-paren param-list scan. A naive split on
+// top-level commas used to lose track of paren depth inside `impl Fn(u8)
+// -> bool`'s own parens and misparse `key` as part of that type, so this
+// mem::forget went unreported.
+fn forget_secret_after_nested_paren_param(pred: impl Fn(u8) -> bool, key: SecretKey) {
+    if pred(0) {
+        mem::forget(key);
+    }
+}
+
 async fn noop_op() {}
 
 // B10: async fn with secret local across .await — stored in Future state machine
@@ -0,0 +1,23 @@
+// Exercises the Z1 rule: secret-bearing structs with no zeroizing Drop.
+
+// Root cause case: raw key bytes, no derive, no Drop impl at all.
+struct SecretKey([u8; 16]);
+
+// A Drop impl exists, but it doesn't actually wipe the storage, so this is
+// still unprotected.
+struct KeyPair {
+    secret: SecretKey,
+}
+
+impl Drop for KeyPair {
+    fn drop(&mut self) {
+        // No-op: forgets to overwrite `self.secret` before it goes away.
+    }
+}
+
+// A bare #[derive(Zeroize)] does NOT clear this: it only adds a
+// `.zeroize()` method, it does not implement Drop, so nothing calls it
+// when this value goes out of scope. Forgetting the `ZeroizeOnDrop` half
+// is exactly the mistake this rule exists to catch.
+#[derive(Zeroize)]
+struct PrivateKey([u8; 32]);
@@ -0,0 +1,20 @@
+// Exercises protective-wrapper suppression: a secret inside a zero-on-drop
+// container should not trip the B1-B10 sinks, even though the operations
+// performed on it would be dangerous on a bare secret.
+use std::mem;
+
+#[derive(ZeroizeOnDrop)]
+struct SecretKey([u8; 32]);
+
+// Received already wrapped: mem::forget on the wrapper is not flagged,
+// since Zeroizing<_> already guarantees the bytes are wiped on drop.
+fn forget_wrapped(key: Zeroizing<SecretKey>) {
+    mem::forget(key);
+}
+
+// Wrapped locally before the dangerous operation: the wrap clears the
+// taint that `raw` carried.
+fn build_then_forget(raw: SecretKey) {
+    let protected = Zeroizing::new(raw);
+    mem::forget(protected);
+}
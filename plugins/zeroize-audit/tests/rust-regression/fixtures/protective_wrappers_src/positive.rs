@@ -0,0 +1,11 @@
+// Contrast case for protective_wrappers_src/negative.rs: the same
+// mem::forget on a *bare* secret (no Zeroizing/Secret/etc wrapper) is
+// still reported.
+use std::mem;
+
+#[derive(ZeroizeOnDrop)]
+struct SecretKey([u8; 32]);
+
+fn forget_bare(key: SecretKey) {
+    mem::forget(key);
+}
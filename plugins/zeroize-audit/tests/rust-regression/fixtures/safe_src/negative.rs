@@ -12,3 +12,40 @@ fn process(p: TelemetryPacket) {
 fn copy_data(src: &[u8]) -> Vec<u8> {
     src.to_vec()
 }
+
+// Z1 should not flag this: the secret-bearing type derives ZeroizeOnDrop.
+#[derive(ZeroizeOnDrop)]
+struct SecretKey([u8; 32]);
+
+// Z1 should not flag this either: pairing #[derive(Zeroize)] with
+// #[zeroize(drop)] is the zeroize crate's other way of wiring up a
+// zeroizing Drop, equivalent to deriving ZeroizeOnDrop outright.
+#[derive(Zeroize)]
+#[zeroize(drop)]
+struct SharedSecret([u8; 32]);
+
+use core::sync::atomic::{self, Ordering};
+use std::mem;
+use std::ptr;
+
+// B6 should not flag this: a volatile write immediately followed by a
+// SeqCst fence is the accepted wipe idiom, not a dead store.
+fn wipe_secret(key: &mut SecretKey) {
+    unsafe { ptr::write_volatile(key as *mut SecretKey, SecretKey([0u8; 32])); }
+    atomic::fence(Ordering::SeqCst);
+}
+
+// B6 should not flag this either: the zeroize crate already performs the
+// volatile-write-then-fence sequence internally.
+fn wipe_secret_with_crate(key: &mut SecretKey) {
+    key.zeroize();
+}
+
+struct PlainData(u8);
+
+// B1 must NOT fire here: `other` is not a secret, even though the tainted
+// `key` appears earlier on the same line (as the receiver of an unrelated
+// call). The sink's own argument list is `other`, not `key`.
+fn unrelated_forget_beside_a_secret(key: SecretKey, other: PlainData) {
+    let _ = key.0.len(); mem::forget(other);
+}
@@ -0,0 +1,46 @@
+// Exercises the T1 (non-constant-time comparison) and D1
+// (Debug/Display leak) rules.
+
+// T1: deriving PartialEq/Eq compiles to a byte-by-byte early-exit compare.
+// D1: deriving Debug lets `{:?}` render the secret bytes.
+#[derive(Debug, PartialEq, Eq)]
+struct SecretKey([u8; 32]);
+
+// D1: a hand-written Display impl is just as much of a leak as deriving Debug.
+struct KeyPair {
+    secret: SecretKey,
+}
+
+impl std::fmt::Display for KeyPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self.secret)
+    }
+}
+
+fn compare_keys(a: &SecretKey, b: &SecretKey) -> bool {
+    // T1: direct == comparison of tainted values.
+    a == b
+}
+
+fn compare_key_field(a: &SecretKey, expected: &[u8; 32]) -> bool {
+    // T1: comparison via a field projection, not the whole value.
+    matches!(a.0, ref bytes if bytes == expected)
+}
+
+fn log_key(key: &SecretKey) {
+    // D1: secret interpolated into a logging macro.
+    println!("key = {:?}", key);
+}
+
+fn unrelated_comparison_beside_a_secret(key: &SecretKey, count: i32) -> bool {
+    // T1 must NOT fire here: `count != 0` doesn't compare the secret at
+    // all, even though a tainted `key` appears earlier in the expression.
+    key.0.len() > 0 && count != 0
+}
+
+fn unrelated_log_beside_a_secret(key: &SecretKey, status: &str) {
+    // D1 must NOT fire here: `println!` only interpolates `status`, even
+    // though a tainted `key` appears earlier on the same line (in the `if`
+    // condition guarding this block).
+    if key.0.len() > 0 { println!("status: {}", status); }
+}